@@ -7,8 +7,17 @@ use std::{
     },
 };
 
+use futures_util::task::AtomicWaker;
 use ringbuf::{Consumer, HeapRb, Producer, SharedRb};
 
+mod oneshot;
+mod stream_sink;
+mod unbounded;
+
+pub use oneshot::{oneshot, OneshotReceiver, OneshotSender};
+pub use stream_sink::SendError;
+pub use unbounded::{nonblocking_unbounded, UnboundedSender};
+
 /// The result of trying to send a message.
 #[must_use]
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
@@ -29,6 +38,7 @@ impl<T> SendResult<T> {
         matches!(self, Self::Ok)
     }
 }
+
 impl<T: std::fmt::Debug> SendResult<T> {
     pub fn unwrap(self) {
         match self {
@@ -42,6 +52,37 @@ impl<T: std::fmt::Debug> SendResult<T> {
     }
 }
 
+/// State shared between a sender and a receiver, beyond the message buffer itself.
+///
+/// Holding the close flag and the two wakers together means both halves always observe a
+/// consistent view of "has the other end gone away, and who (if anyone) is parked waiting".
+pub(crate) struct Shared {
+    is_closed: AtomicBool,
+    recv_waker: AtomicWaker,
+    send_waker: AtomicWaker,
+}
+
+impl Shared {
+    fn new() -> Self {
+        Self {
+            is_closed: AtomicBool::new(false),
+            recv_waker: AtomicWaker::new(),
+            send_waker: AtomicWaker::new(),
+        }
+    }
+
+    fn close(&self) {
+        self.is_closed.store(true, Ordering::SeqCst);
+        // Wake both ends: whichever side is parked needs the chance to observe the closure.
+        self.recv_waker.wake();
+        self.send_waker.wake();
+    }
+
+    fn is_closed(&self) -> bool {
+        self.is_closed.load(Ordering::SeqCst)
+    }
+}
+
 /// A SPSC sender guaranteed to never block when sending a message. This is a strong constraint, enforced
 /// by WebAssembly on the main thread, so this should only be preferred over other mpsc channels where
 /// non-blocking behaviour is *required*.
@@ -49,8 +90,8 @@ impl<T: std::fmt::Debug> SendResult<T> {
 /// Cannot be cloned, so if you want multiple clients to send messages then you need a sender that may block
 /// for very short periods when sending a message - see [`NonBlockingSender::mpsc`].
 pub struct NonBlockingSender<T> {
-    inner: Producer<T, Arc<SharedRb<T, Vec<MaybeUninit<T>>>>>,
-    is_closed: Arc<AtomicBool>,
+    pub(crate) inner: Producer<T, Arc<SharedRb<T, Vec<MaybeUninit<T>>>>>,
+    pub(crate) shared: Arc<Shared>,
 }
 impl<T> NonBlockingSender<T> {
     /// Tries to send a message to the receiving channel without ever blocking, even briefly.
@@ -59,13 +100,15 @@ impl<T> NonBlockingSender<T> {
     ///
     /// This method fails if the receiving queue is full, or if the receiver has been dropped.
     pub fn try_send(&mut self, message: T) -> SendResult<T> {
-        if self.is_closed.load(Ordering::SeqCst) {
+        if self.shared.is_closed() {
             SendResult::Disconnected
         } else {
             let res = self.inner.push(message);
             if let Err(message) = res {
                 SendResult::Full(message)
             } else {
+                // A new message arrived, so give a parked `Stream` a chance to wake.
+                self.shared.recv_waker.wake();
                 SendResult::Ok
             }
         }
@@ -77,12 +120,48 @@ impl<T> NonBlockingSender<T> {
             inner: Arc::new(Mutex::new(self)),
         };
     }
+
+    /// The number of further messages that can be sent before the channel is full.
+    pub fn remaining(&self) -> usize {
+        self.inner.free_len()
+    }
+
+    /// The total number of messages the channel can hold at once.
+    pub fn capacity(&self) -> usize {
+        self.inner.capacity()
+    }
+
+    /// Whether the channel currently has no remaining capacity to send into.
+    pub fn is_full(&self) -> bool {
+        self.remaining() == 0
+    }
+}
+
+impl<T: Copy> NonBlockingSender<T> {
+    /// Sends as many of `items` as currently fit, without ever blocking, checking whether the
+    /// receiver has disconnected only once rather than once per element.
+    ///
+    /// # Result
+    ///
+    /// Returns the number of leading elements of `items` that were sent; this is `0` if the
+    /// receiver has disconnected.
+    pub fn try_send_slice(&mut self, items: &[T]) -> usize {
+        if self.shared.is_closed() {
+            return 0;
+        }
+        let written = self.inner.push_slice(items);
+        if written > 0 {
+            // Capacity was consumed, but more importantly new data arrived for a parked `Stream`.
+            self.shared.recv_waker.wake();
+        }
+        written
+    }
 }
 
 impl<T> Drop for NonBlockingSender<T> {
     fn drop(&mut self) {
         // Cascade closures
-        self.is_closed.store(true, Ordering::SeqCst);
+        self.shared.close();
     }
 }
 
@@ -141,35 +220,135 @@ impl<T> RecvResult<T> {
     }
 }
 
+/// The receiving side's backing storage: either the bounded ring buffer used by
+/// [`nonblocking_channel`], or the unbounded lock-free queue used by [`nonblocking_unbounded`].
+///
+/// [`NonBlockingReceiver`] is deliberately the same type for both, so callers don't need a
+/// separate unbounded receiver type to learn.
+pub(crate) enum ReceiverInner<T> {
+    Bounded(Consumer<T, Arc<SharedRb<T, Vec<MaybeUninit<T>>>>>),
+    Unbounded(Arc<unbounded::Queue<T>>),
+}
+
+impl<T> ReceiverInner<T> {
+    fn pop(&mut self) -> Option<T> {
+        match self {
+            ReceiverInner::Bounded(consumer) => consumer.pop(),
+            ReceiverInner::Unbounded(queue) => queue.pop(),
+        }
+    }
+
+    fn len(&self) -> usize {
+        match self {
+            ReceiverInner::Bounded(consumer) => consumer.len(),
+            ReceiverInner::Unbounded(queue) => queue.len(),
+        }
+    }
+}
+
+impl<T: Copy> ReceiverInner<T> {
+    /// Pops up to `buf.len()` elements into `buf`, returning how many were written.
+    ///
+    /// The ring buffer exposes this as a single contiguous-slice copy; the linked-segment
+    /// unbounded queue has no such contiguous view, so it falls back to popping element-by-element.
+    fn pop_slice(&mut self, buf: &mut [T]) -> usize {
+        match self {
+            ReceiverInner::Bounded(consumer) => consumer.pop_slice(buf),
+            ReceiverInner::Unbounded(queue) => {
+                let mut written = 0;
+                for slot in buf {
+                    match queue.pop() {
+                        Some(value) => *slot = value,
+                        None => break,
+                    }
+                    written += 1;
+                }
+                written
+            }
+        }
+    }
+}
+
 /// A receiver that is guaranteed to never block when receiving messages.
 pub struct NonBlockingReceiver<T> {
-    inner: Consumer<T, Arc<SharedRb<T, Vec<MaybeUninit<T>>>>>,
-    is_closed: Arc<AtomicBool>,
+    pub(crate) inner: ReceiverInner<T>,
+    pub(crate) shared: Arc<Shared>,
 }
 impl<T> NonBlockingReceiver<T> {
-    /// Tries to send a message to the receiving channel without ever blocking, even briefly.
+    /// Tries to receive a message from the channel without ever blocking, even briefly.
     ///
     /// # Result
     ///
-    /// This method fails if the receiving queue is full, or if the receiver has been dropped.
+    /// Messages already buffered are returned even after the sender has disconnected - this
+    /// method only reports [`RecvResult::Disconnected`] once the buffer has been fully drained.
     pub fn try_recv(&mut self) -> RecvResult<T> {
-        if self.is_closed.load(Ordering::SeqCst) {
+        if let Some(message) = self.inner.pop() {
+            // Freed a slot, so give a parked `Sink` a chance to wake.
+            self.shared.send_waker.wake();
+            RecvResult::Ok(message)
+        } else if self.shared.is_closed() {
             RecvResult::Disconnected
         } else {
-            let res = self.inner.pop();
-            if let Some(message) = res {
-                RecvResult::Ok(message)
-            } else {
-                RecvResult::Empty
-            }
+            RecvResult::Empty
+        }
+    }
+
+    /// Returns a draining iterator over whatever messages are currently available, without ever
+    /// blocking; the iterator stops - without signalling disconnection - as soon as the channel
+    /// is empty or disconnected.
+    pub fn recv_iter(&mut self) -> RecvIter<'_, T> {
+        RecvIter { receiver: self }
+    }
+
+    /// The number of messages currently buffered and ready to receive.
+    pub fn len(&self) -> usize {
+        self.inner.len()
+    }
+
+    /// Whether there are no messages currently buffered.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+impl<T: Copy> NonBlockingReceiver<T> {
+    /// Receives up to `buf.len()` messages into `buf`, without ever blocking.
+    ///
+    /// # Result
+    ///
+    /// Returns the number of leading elements of `buf` that were filled; this is `0` if the
+    /// channel is empty. Messages still buffered after the sender has disconnected are returned.
+    pub fn try_recv_slice(&mut self, buf: &mut [T]) -> usize {
+        let written = self.inner.pop_slice(buf);
+        if written > 0 {
+            // Freed up slots, so give a parked `Sink` a chance to wake.
+            self.shared.send_waker.wake();
         }
+        written
     }
 }
 
 impl<T> Drop for NonBlockingReceiver<T> {
     fn drop(&mut self) {
         // Cascade closures
-        self.is_closed.store(true, Ordering::SeqCst);
+        self.shared.close();
+    }
+}
+
+/// A draining iterator over the messages currently available on a [`NonBlockingReceiver`],
+/// created by [`NonBlockingReceiver::recv_iter`].
+pub struct RecvIter<'a, T> {
+    receiver: &'a mut NonBlockingReceiver<T>,
+}
+
+impl<'a, T> Iterator for RecvIter<'a, T> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<T> {
+        match self.receiver.try_recv() {
+            RecvResult::Ok(message) => Some(message),
+            RecvResult::Empty | RecvResult::Disconnected => None,
+        }
     }
 }
 
@@ -177,15 +356,15 @@ pub fn nonblocking_channel<T>(
     capacity: NonZeroUsize,
 ) -> (NonBlockingSender<T>, NonBlockingReceiver<T>) {
     let (sender, receiver) = HeapRb::<T>::new(capacity.get()).split();
-    let is_closed = Arc::new(AtomicBool::from(false));
+    let shared = Arc::new(Shared::new());
 
     let sender = NonBlockingSender {
         inner: sender,
-        is_closed: Arc::clone(&is_closed),
+        shared: Arc::clone(&shared),
     };
     let receiver = NonBlockingReceiver {
-        inner: receiver,
-        is_closed,
+        inner: ReceiverInner::Bounded(receiver),
+        shared,
     };
 
     return (sender, receiver);
@@ -0,0 +1,146 @@
+use std::{
+    cell::UnsafeCell,
+    sync::{
+        atomic::{AtomicU8, Ordering},
+        Arc,
+    },
+};
+
+use crate::RecvResult;
+
+const EMPTY: u8 = 0;
+const SENT: u8 = 1;
+const RECEIVER_DROPPED: u8 = 2;
+const SENDER_DROPPED: u8 = 3;
+
+struct Shared<T> {
+    state: AtomicU8,
+    value: UnsafeCell<Option<T>>,
+}
+
+// Safety: `value` is only ever written by the sender before it publishes `SENT`, and only ever
+// read by the receiver after it observes `SENT` (or taken back by the sender itself when the
+// receiver was already dropped) - the `state` handshake is the synchronisation.
+unsafe impl<T: Send> Send for Shared<T> {}
+unsafe impl<T: Send> Sync for Shared<T> {}
+
+/// The sending half of a [`oneshot`] channel.
+pub struct OneshotSender<T> {
+    shared: Arc<Shared<T>>,
+}
+
+impl<T> OneshotSender<T> {
+    /// Sends the single value this channel will ever carry, without ever blocking.
+    ///
+    /// # Result
+    ///
+    /// Fails with the value handed back if the receiver has already been dropped.
+    pub fn send(self, value: T) -> Result<(), T> {
+        // Safety: we are the only sender (this method consumes `self`), and nobody reads
+        // `value` until they observe `SENT` below, so writing it first is sound.
+        unsafe { *self.shared.value.get() = Some(value) };
+
+        match self
+            .shared
+            .state
+            .compare_exchange(EMPTY, SENT, Ordering::Release, Ordering::Acquire)
+        {
+            Ok(_) => Ok(()),
+            Err(_) => {
+                // The receiver dropped before we could send - reclaim the value we just wrote.
+                let value = unsafe { (*self.shared.value.get()).take() }
+                    .expect("value was just written by this sender");
+                Err(value)
+            }
+        }
+    }
+
+    /// Checks whether the receiver has been dropped, so a caller can skip producing a value it
+    /// knows nobody will ever read.
+    pub fn is_canceled(&self) -> bool {
+        self.shared.state.load(Ordering::Acquire) == RECEIVER_DROPPED
+    }
+}
+
+impl<T> Drop for OneshotSender<T> {
+    fn drop(&mut self) {
+        // This also runs at the end of `send`, but by then the state is no longer `EMPTY` and
+        // the exchange below does nothing. Otherwise `send` was never called: cascade the
+        // closure so the receiver's next `try_recv` reports `Disconnected`.
+        let _ =
+            self.shared
+                .state
+                .compare_exchange(EMPTY, SENDER_DROPPED, Ordering::Release, Ordering::Relaxed);
+    }
+}
+
+/// The receiving half of a [`oneshot`] channel.
+pub struct OneshotReceiver<T> {
+    shared: Arc<Shared<T>>,
+    done: bool,
+}
+
+impl<T> OneshotReceiver<T> {
+    /// Tries to receive the value without ever blocking, even briefly.
+    ///
+    /// # Result
+    ///
+    /// This fails with [`RecvResult::Disconnected`] if the sender dropped without sending, and
+    /// continues to report [`RecvResult::Disconnected`] on every call after the value has
+    /// already been taken.
+    pub fn try_recv(&mut self) -> RecvResult<T> {
+        if self.done {
+            return RecvResult::Disconnected;
+        }
+
+        match self.shared.state.load(Ordering::Acquire) {
+            SENT => {
+                self.done = true;
+                // Safety: we observed `SENT`, so the sender's write to `value` happened-before
+                // this read.
+                let value = unsafe { (*self.shared.value.get()).take() }
+                    .expect("value was written before state became SENT");
+                RecvResult::Ok(value)
+            }
+            SENDER_DROPPED => {
+                self.done = true;
+                RecvResult::Disconnected
+            }
+            _ => RecvResult::Empty,
+        }
+    }
+}
+
+impl<T> Drop for OneshotReceiver<T> {
+    fn drop(&mut self) {
+        // If the sender hasn't sent (or dropped) yet, mark the receiver as gone so a concurrent
+        // `send` can hand the value back instead of sending it into the void.
+        let _ = self.shared.state.compare_exchange(
+            EMPTY,
+            RECEIVER_DROPPED,
+            Ordering::Release,
+            Ordering::Relaxed,
+        );
+    }
+}
+
+/// Creates a non-blocking, single-value "oneshot" channel, modeled on
+/// `futures_channel::oneshot`: a move-only handoff for request/response style code that would
+/// otherwise need a capacity-1 [`nonblocking_channel`](crate::nonblocking_channel) only to drop
+/// it after one message.
+pub fn oneshot<T>() -> (OneshotSender<T>, OneshotReceiver<T>) {
+    let shared = Arc::new(Shared {
+        state: AtomicU8::new(EMPTY),
+        value: UnsafeCell::new(None),
+    });
+
+    let sender = OneshotSender {
+        shared: Arc::clone(&shared),
+    };
+    let receiver = OneshotReceiver {
+        shared,
+        done: false,
+    };
+
+    (sender, receiver)
+}
@@ -0,0 +1,135 @@
+use std::{
+    fmt,
+    pin::Pin,
+    task::{Context, Poll},
+};
+
+use futures_core::Stream;
+use futures_sink::Sink;
+
+use crate::{NonBlockingReceiver, NonBlockingSender};
+
+/// The error returned by the [`Sink`] implementation of [`NonBlockingSender`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SendError(SendErrorKind);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum SendErrorKind {
+    Full,
+    Disconnected,
+}
+
+impl SendError {
+    /// Returns `true` if the channel was full, rather than disconnected.
+    pub fn is_full(&self) -> bool {
+        matches!(self.0, SendErrorKind::Full)
+    }
+
+    /// Returns `true` if the receiver had been dropped.
+    pub fn is_disconnected(&self) -> bool {
+        matches!(self.0, SendErrorKind::Disconnected)
+    }
+}
+
+impl fmt::Display for SendError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.0 {
+            SendErrorKind::Full => write!(f, "channel is full"),
+            SendErrorKind::Disconnected => write!(f, "receiver was disconnected"),
+        }
+    }
+}
+
+impl std::error::Error for SendError {}
+
+impl<T> Stream for NonBlockingReceiver<T> {
+    type Item = T;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<T>> {
+        // Safety: `NonBlockingReceiver` has no self-referential fields, so moving it is always
+        // sound regardless of `T`; nothing here relies on `Pin`'s guarantees.
+        let this = unsafe { self.get_unchecked_mut() };
+
+        // Try once, then register and re-check: without the re-check, a value pushed between our
+        // failed `pop` and the registration would leave us parked with no further wake-up coming.
+        if let Some(message) = this.inner.pop() {
+            this.shared.send_waker.wake();
+            return Poll::Ready(Some(message));
+        }
+        if this.shared.is_closed() {
+            return Poll::Ready(None);
+        }
+
+        this.shared.recv_waker.register(cx.waker());
+
+        if let Some(message) = this.inner.pop() {
+            this.shared.send_waker.wake();
+            Poll::Ready(Some(message))
+        } else if this.shared.is_closed() {
+            Poll::Ready(None)
+        } else {
+            Poll::Pending
+        }
+    }
+}
+
+impl<T> Sink<T> for NonBlockingSender<T> {
+    type Error = SendError;
+
+    fn poll_ready(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Result<(), SendError>> {
+        // Safety: `NonBlockingSender` has no self-referential fields, so moving it is always
+        // sound regardless of `T`; nothing here relies on `Pin`'s guarantees.
+        let this = unsafe { self.get_unchecked_mut() };
+
+        if this.shared.is_closed() {
+            return Poll::Ready(Err(SendError(SendErrorKind::Disconnected)));
+        }
+        if this.inner.free_len() > 0 {
+            return Poll::Ready(Ok(()));
+        }
+
+        this.shared.send_waker.register(cx.waker());
+
+        if this.shared.is_closed() {
+            Poll::Ready(Err(SendError(SendErrorKind::Disconnected)))
+        } else if this.inner.free_len() > 0 {
+            Poll::Ready(Ok(()))
+        } else {
+            Poll::Pending
+        }
+    }
+
+    fn start_send(self: Pin<&mut Self>, item: T) -> Result<(), SendError> {
+        // Safety: see `poll_ready`.
+        let this = unsafe { self.get_unchecked_mut() };
+
+        if this.shared.is_closed() {
+            return Err(SendError(SendErrorKind::Disconnected));
+        }
+        match this.inner.push(item) {
+            Ok(()) => {
+                this.shared.recv_waker.wake();
+                Ok(())
+            }
+            // `poll_ready` is required to have returned `Ready(Ok(()))` immediately before this
+            // call, so this only fires if a caller violates the `Sink` contract.
+            Err(_) => Err(SendError(SendErrorKind::Full)),
+        }
+    }
+
+    fn poll_flush(self: Pin<&mut Self>, _cx: &mut Context<'_>) -> Poll<Result<(), SendError>> {
+        // Every `start_send` is written straight into the ring buffer, so there is never
+        // anything left to flush.
+        Poll::Ready(Ok(()))
+    }
+
+    fn poll_close(self: Pin<&mut Self>, _cx: &mut Context<'_>) -> Poll<Result<(), SendError>> {
+        // Safety: see `poll_ready`.
+        let this = unsafe { self.get_unchecked_mut() };
+
+        // Closing the sink is indistinguishable from dropping it: the paired `Stream` should see
+        // end-of-input once buffered messages are drained, not keep parking forever.
+        this.shared.close();
+        Poll::Ready(Ok(()))
+    }
+}
@@ -0,0 +1,98 @@
+use std::sync::Arc;
+
+use crossbeam_queue::SegQueue;
+
+use crate::{NonBlockingReceiver, ReceiverInner, SendResult, Shared};
+
+/// The unbounded receiver's backing storage.
+///
+/// A hand-rolled intrusive linked queue cannot free a retired node the moment the single
+/// consumer pops past it: a producer may already hold a stale `tail` snapshot of that very node
+/// and be about to dereference it, which is a use-after-free without hazard pointers or an
+/// epoch-based reclaimer. Rather than hand-roll that reclamation scheme, this wraps
+/// [`crossbeam_queue::SegQueue`], a proven lock-free MPMC queue that already solves it.
+pub(crate) struct Queue<T>(SegQueue<T>);
+
+impl<T> Queue<T> {
+    fn new() -> Self {
+        Self(SegQueue::new())
+    }
+
+    pub(crate) fn push(&self, value: T) {
+        self.0.push(value);
+    }
+
+    pub(crate) fn pop(&self) -> Option<T> {
+        self.0.pop()
+    }
+
+    pub(crate) fn len(&self) -> usize {
+        self.0.len()
+    }
+}
+
+struct Inner<T> {
+    queue: Arc<Queue<T>>,
+    shared: Arc<Shared>,
+}
+
+impl<T> Drop for Inner<T> {
+    fn drop(&mut self) {
+        self.shared.close();
+    }
+}
+
+/// The sender half of a [`nonblocking_unbounded`] channel.
+///
+/// Unlike [`NonBlockingSender`](crate::NonBlockingSender), this can be cloned to give multiple
+/// producers, and `try_send` can never report the channel full.
+pub struct UnboundedSender<T> {
+    inner: Arc<Inner<T>>,
+}
+
+impl<T> UnboundedSender<T> {
+    /// Tries to send a message without ever blocking, even briefly.
+    ///
+    /// # Result
+    ///
+    /// This can only ever return [`SendResult::Ok`] or [`SendResult::Disconnected`] - there is
+    /// no capacity to exhaust.
+    pub fn try_send(&self, message: T) -> SendResult<T> {
+        if self.inner.shared.is_closed() {
+            SendResult::Disconnected
+        } else {
+            self.inner.queue.push(message);
+            self.inner.shared.recv_waker.wake();
+            SendResult::Ok
+        }
+    }
+}
+
+impl<T> Clone for UnboundedSender<T> {
+    fn clone(&self) -> Self {
+        Self {
+            inner: Arc::clone(&self.inner),
+        }
+    }
+}
+
+/// Creates an unbounded non-blocking channel: a multi-producer queue whose `try_send` never
+/// blocks and never fails with "full", paired with the same never-blocking
+/// [`NonBlockingReceiver`] used by [`nonblocking_channel`](crate::nonblocking_channel).
+pub fn nonblocking_unbounded<T>() -> (UnboundedSender<T>, NonBlockingReceiver<T>) {
+    let queue = Arc::new(Queue::new());
+    let shared = Arc::new(Shared::new());
+
+    let sender = UnboundedSender {
+        inner: Arc::new(Inner {
+            queue: Arc::clone(&queue),
+            shared: Arc::clone(&shared),
+        }),
+    };
+    let receiver = NonBlockingReceiver {
+        inner: ReceiverInner::Unbounded(queue),
+        shared,
+    };
+
+    (sender, receiver)
+}
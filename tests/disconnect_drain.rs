@@ -0,0 +1,46 @@
+use std::num::NonZeroUsize;
+
+use nonblocking_channel::RecvResult;
+use wasm_bindgen_test::wasm_bindgen_test;
+
+fn check_drains_after_disconnect(capacity: usize) {
+    let (mut sender, mut receiver) =
+        nonblocking_channel::nonblocking_channel(NonZeroUsize::new(capacity).unwrap());
+
+    for i in 0..capacity {
+        sender.try_send(i).unwrap()
+    }
+
+    drop(sender);
+
+    for i in 0..capacity {
+        assert_eq!(receiver.try_recv(), RecvResult::Ok(i));
+    }
+    assert_eq!(receiver.try_recv(), RecvResult::Disconnected);
+}
+
+#[test]
+fn native_check_drains_after_disconnect_1() {
+    check_drains_after_disconnect(1)
+}
+#[test]
+fn native_check_drains_after_disconnect_16() {
+    check_drains_after_disconnect(16)
+}
+#[test]
+fn native_check_drains_after_disconnect_127() {
+    check_drains_after_disconnect(127)
+}
+
+#[wasm_bindgen_test]
+fn web_check_drains_after_disconnect_1() {
+    check_drains_after_disconnect(1)
+}
+#[wasm_bindgen_test]
+fn web_check_drains_after_disconnect_16() {
+    check_drains_after_disconnect(16)
+}
+#[wasm_bindgen_test]
+fn web_check_drains_after_disconnect_127() {
+    check_drains_after_disconnect(127)
+}
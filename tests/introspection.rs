@@ -0,0 +1,63 @@
+use std::num::NonZeroUsize;
+
+use wasm_bindgen_test::wasm_bindgen_test;
+
+fn check_bounded_introspection(capacity: usize) {
+    let (mut sender, mut receiver) =
+        nonblocking_channel::nonblocking_channel(NonZeroUsize::new(capacity).unwrap());
+
+    assert_eq!(sender.capacity(), capacity);
+    assert_eq!(sender.remaining(), capacity);
+    assert!(!sender.is_full());
+    assert_eq!(receiver.len(), 0);
+    assert!(receiver.is_empty());
+
+    for i in 0..capacity {
+        sender.try_send(i).unwrap();
+        assert_eq!(sender.remaining(), capacity - i - 1);
+        assert_eq!(receiver.len(), i + 1);
+    }
+    assert!(sender.is_full());
+    assert!(!receiver.is_empty());
+
+    for i in 0..capacity {
+        receiver.try_recv().unwrap();
+        assert_eq!(sender.remaining(), capacity - (capacity - i - 1));
+        assert_eq!(receiver.len(), capacity - i - 1);
+    }
+    assert!(receiver.is_empty());
+}
+
+fn check_unbounded_introspection(count: usize) {
+    let (sender, mut receiver) = nonblocking_channel::nonblocking_unbounded();
+
+    assert!(receiver.is_empty());
+    for i in 0..count {
+        sender.try_send(i).unwrap();
+        assert_eq!(receiver.len(), i + 1);
+    }
+
+    for i in 0..count {
+        receiver.try_recv().unwrap();
+        assert_eq!(receiver.len(), count - i - 1);
+    }
+    assert!(receiver.is_empty());
+}
+
+#[test]
+fn native_check_bounded_introspection_16() {
+    check_bounded_introspection(16)
+}
+#[test]
+fn native_check_unbounded_introspection_16() {
+    check_unbounded_introspection(16)
+}
+
+#[wasm_bindgen_test]
+fn web_check_bounded_introspection_16() {
+    check_bounded_introspection(16)
+}
+#[wasm_bindgen_test]
+fn web_check_unbounded_introspection_16() {
+    check_unbounded_introspection(16)
+}
@@ -0,0 +1,67 @@
+use nonblocking_channel::RecvResult;
+use wasm_bindgen_test::wasm_bindgen_test;
+
+fn check_sends_single_value() {
+    let (sender, mut receiver) = nonblocking_channel::oneshot();
+
+    assert_eq!(receiver.try_recv(), RecvResult::Empty);
+    sender.send(42).unwrap();
+    assert_eq!(receiver.try_recv(), RecvResult::Ok(42));
+    assert_eq!(receiver.try_recv(), RecvResult::Disconnected);
+}
+
+fn check_disconnects_if_sender_dropped() {
+    let (sender, mut receiver) = nonblocking_channel::oneshot::<i32>();
+
+    drop(sender);
+    assert_eq!(receiver.try_recv(), RecvResult::Disconnected);
+}
+
+fn check_send_fails_if_receiver_dropped() {
+    let (sender, receiver) = nonblocking_channel::oneshot();
+
+    drop(receiver);
+    assert_eq!(sender.send(42), Err(42));
+}
+
+fn check_is_canceled_after_receiver_dropped() {
+    let (sender, receiver) = nonblocking_channel::oneshot::<i32>();
+
+    assert!(!sender.is_canceled());
+    drop(receiver);
+    assert!(sender.is_canceled());
+}
+
+#[test]
+fn native_check_sends_single_value() {
+    check_sends_single_value()
+}
+#[test]
+fn native_check_disconnects_if_sender_dropped() {
+    check_disconnects_if_sender_dropped()
+}
+#[test]
+fn native_check_send_fails_if_receiver_dropped() {
+    check_send_fails_if_receiver_dropped()
+}
+#[test]
+fn native_check_is_canceled_after_receiver_dropped() {
+    check_is_canceled_after_receiver_dropped()
+}
+
+#[wasm_bindgen_test]
+fn web_check_sends_single_value() {
+    check_sends_single_value()
+}
+#[wasm_bindgen_test]
+fn web_check_disconnects_if_sender_dropped() {
+    check_disconnects_if_sender_dropped()
+}
+#[wasm_bindgen_test]
+fn web_check_send_fails_if_receiver_dropped() {
+    check_send_fails_if_receiver_dropped()
+}
+#[wasm_bindgen_test]
+fn web_check_is_canceled_after_receiver_dropped() {
+    check_is_canceled_after_receiver_dropped()
+}
@@ -0,0 +1,56 @@
+use std::num::NonZeroUsize;
+
+use wasm_bindgen_test::wasm_bindgen_test;
+
+fn check_send_recv_slice(capacity: usize) {
+    let (mut sender, mut receiver) =
+        nonblocking_channel::nonblocking_channel(NonZeroUsize::new(capacity).unwrap());
+
+    let items: Vec<usize> = (0..capacity).collect();
+    assert_eq!(sender.try_send_slice(&items), capacity);
+    // The channel is now full, so nothing more fits.
+    assert_eq!(sender.try_send_slice(&[capacity]), 0);
+
+    let mut buf = vec![0usize; capacity + 1];
+    assert_eq!(receiver.try_recv_slice(&mut buf), capacity);
+    assert_eq!(&buf[..capacity], &items[..]);
+}
+
+fn check_recv_iter_drains_available_messages(capacity: usize) {
+    let (mut sender, mut receiver) =
+        nonblocking_channel::nonblocking_channel(NonZeroUsize::new(capacity).unwrap());
+
+    for i in 0..capacity {
+        sender.try_send(i).unwrap();
+    }
+
+    let received: Vec<usize> = receiver.recv_iter().collect();
+    assert_eq!(received, (0..capacity).collect::<Vec<_>>());
+    assert_eq!(receiver.recv_iter().count(), 0);
+}
+
+#[test]
+fn native_check_send_recv_slice_16() {
+    check_send_recv_slice(16)
+}
+#[test]
+fn native_check_send_recv_slice_127() {
+    check_send_recv_slice(127)
+}
+#[test]
+fn native_check_recv_iter_drains_available_messages_16() {
+    check_recv_iter_drains_available_messages(16)
+}
+
+#[wasm_bindgen_test]
+fn web_check_send_recv_slice_16() {
+    check_send_recv_slice(16)
+}
+#[wasm_bindgen_test]
+fn web_check_send_recv_slice_127() {
+    check_send_recv_slice(127)
+}
+#[wasm_bindgen_test]
+fn web_check_recv_iter_drains_available_messages_16() {
+    check_recv_iter_drains_available_messages(16)
+}
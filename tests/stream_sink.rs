@@ -0,0 +1,113 @@
+use std::{
+    num::NonZeroUsize,
+    pin::Pin,
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc,
+    },
+    task::{Context, Poll, Wake, Waker},
+};
+
+use futures_core::Stream;
+use futures_sink::Sink;
+use wasm_bindgen_test::wasm_bindgen_test;
+
+/// A waker that just records whether it was ever woken, for polling by hand without an executor.
+struct FlagWaker(AtomicBool);
+
+impl Wake for FlagWaker {
+    fn wake(self: Arc<Self>) {
+        self.0.store(true, Ordering::SeqCst);
+    }
+
+    fn wake_by_ref(self: &Arc<Self>) {
+        self.0.store(true, Ordering::SeqCst);
+    }
+}
+
+fn flag_waker() -> (Arc<FlagWaker>, Waker) {
+    let flag = Arc::new(FlagWaker(AtomicBool::new(false)));
+    let waker = Waker::from(Arc::clone(&flag));
+    (flag, waker)
+}
+
+fn check_poll_next_wakes_on_send(capacity: usize) {
+    let (mut sender, mut receiver) =
+        nonblocking_channel::nonblocking_channel(NonZeroUsize::new(capacity).unwrap());
+
+    let (flag, waker) = flag_waker();
+    let mut cx = Context::from_waker(&waker);
+
+    assert_eq!(Pin::new(&mut receiver).poll_next(&mut cx), Poll::Pending);
+    assert!(!flag.0.load(Ordering::SeqCst));
+
+    sender.try_send(42).unwrap();
+    assert!(flag.0.load(Ordering::SeqCst));
+
+    assert_eq!(
+        Pin::new(&mut receiver).poll_next(&mut cx),
+        Poll::Ready(Some(42))
+    );
+}
+
+fn check_poll_ready_wakes_on_recv(capacity: usize) {
+    let (mut sender, mut receiver) =
+        nonblocking_channel::nonblocking_channel(NonZeroUsize::new(capacity).unwrap());
+
+    for i in 0..capacity {
+        sender.try_send(i).unwrap();
+    }
+
+    let (flag, waker) = flag_waker();
+    let mut cx = Context::from_waker(&waker);
+
+    assert_eq!(Pin::new(&mut sender).poll_ready(&mut cx), Poll::Pending);
+    assert!(!flag.0.load(Ordering::SeqCst));
+
+    assert!(receiver.try_recv().is_ok());
+    assert!(flag.0.load(Ordering::SeqCst));
+
+    assert_eq!(Pin::new(&mut sender).poll_ready(&mut cx), Poll::Ready(Ok(())));
+}
+
+fn check_poll_next_wakes_on_sender_drop(capacity: usize) {
+    let (sender, mut receiver) =
+        nonblocking_channel::nonblocking_channel::<usize>(NonZeroUsize::new(capacity).unwrap());
+
+    let (flag, waker) = flag_waker();
+    let mut cx = Context::from_waker(&waker);
+
+    assert_eq!(Pin::new(&mut receiver).poll_next(&mut cx), Poll::Pending);
+    assert!(!flag.0.load(Ordering::SeqCst));
+
+    drop(sender);
+    assert!(flag.0.load(Ordering::SeqCst));
+
+    assert_eq!(Pin::new(&mut receiver).poll_next(&mut cx), Poll::Ready(None));
+}
+
+#[test]
+fn native_check_poll_next_wakes_on_send_16() {
+    check_poll_next_wakes_on_send(16)
+}
+#[test]
+fn native_check_poll_ready_wakes_on_recv_16() {
+    check_poll_ready_wakes_on_recv(16)
+}
+#[test]
+fn native_check_poll_next_wakes_on_sender_drop_16() {
+    check_poll_next_wakes_on_sender_drop(16)
+}
+
+#[wasm_bindgen_test]
+fn web_check_poll_next_wakes_on_send_16() {
+    check_poll_next_wakes_on_send(16)
+}
+#[wasm_bindgen_test]
+fn web_check_poll_ready_wakes_on_recv_16() {
+    check_poll_ready_wakes_on_recv(16)
+}
+#[wasm_bindgen_test]
+fn web_check_poll_next_wakes_on_sender_drop_16() {
+    check_poll_next_wakes_on_sender_drop(16)
+}
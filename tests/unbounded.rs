@@ -0,0 +1,52 @@
+use nonblocking_channel::{RecvResult, SendResult};
+use wasm_bindgen_test::wasm_bindgen_test;
+
+fn check_never_reports_full(count: usize) {
+    let (sender, mut receiver) = nonblocking_channel::nonblocking_unbounded();
+
+    for i in 0..count {
+        assert_eq!(sender.try_send(i), SendResult::Ok);
+    }
+
+    for i in 0..count {
+        assert_eq!(receiver.try_recv(), RecvResult::Ok(i));
+    }
+    assert_eq!(receiver.try_recv(), RecvResult::Empty);
+}
+
+fn check_drains_after_all_senders_dropped(count: usize) {
+    let (sender, mut receiver) = nonblocking_channel::nonblocking_unbounded();
+    let other_sender = sender.clone();
+
+    for i in 0..count {
+        other_sender.try_send(i).unwrap();
+    }
+
+    // Dropping one clone must not close the channel while another is still alive.
+    drop(sender);
+    assert_eq!(other_sender.try_send(count), SendResult::Ok);
+    drop(other_sender);
+
+    for i in 0..=count {
+        assert_eq!(receiver.try_recv(), RecvResult::Ok(i));
+    }
+    assert_eq!(receiver.try_recv(), RecvResult::Disconnected);
+}
+
+#[test]
+fn native_check_never_reports_full_1000() {
+    check_never_reports_full(1000)
+}
+#[test]
+fn native_check_drains_after_all_senders_dropped_16() {
+    check_drains_after_all_senders_dropped(16)
+}
+
+#[wasm_bindgen_test]
+fn web_check_never_reports_full_1000() {
+    check_never_reports_full(1000)
+}
+#[wasm_bindgen_test]
+fn web_check_drains_after_all_senders_dropped_16() {
+    check_drains_after_all_senders_dropped(16)
+}